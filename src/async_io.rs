@@ -0,0 +1,476 @@
+use std::io::Result;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+#[cfg(feature = "batteries-included")]
+use std::collections::HashMap;
+#[cfg(feature = "batteries-included")]
+use std::hash::{BuildHasher, Hash};
+
+use crate::{ByteOrder, Settings};
+
+const MAX_VARINT_BYTES: u32 = 10;
+
+/// Rejects a collection length prefix that exceeds `max_collection_len`, so a
+/// hostile length prefix can't be used to trigger an oversized up-front
+/// allocation. Mirrors the sync side's `read_len` check in `lib.rs`.
+#[cfg(feature = "batteries-included")]
+fn check_collection_len(len: u64, max_collection_len: u64) -> Result<()> {
+    if len > max_collection_len {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("collection length {len} exceeds the configured maximum of {max_collection_len}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Async counterpart to [`crate::read_varint`].
+async fn read_varint<R: AsyncRead + Unpin + Send>(buffer: &mut R) -> Result<u64> {
+    let mut result = 0u64;
+    let mut byte = [0u8; 1];
+    for i in 0..MAX_VARINT_BYTES {
+        buffer.read_exact(&mut byte).await?;
+        result |= u64::from(byte[0] & 0x7f) << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "varint exceeded the maximum number of continuation bytes",
+    ))
+}
+
+/// Async counterpart to [`crate::write_varint`].
+async fn write_varint<W: AsyncWrite + Unpin + Send>(buffer: &mut W, mut value: u64) -> Result<()> {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buffer.write_all(&[byte]).await?;
+        if value == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Allows for asynchronously reading something from a buffer.
+///
+/// This is the async counterpart to [`StreamReader`](crate::StreamReader),
+/// built on top of tokio's [`AsyncRead`].
+pub trait AsyncStreamReader: Sized {
+    /// Reads something from the specified buffer using the given byte order.
+    fn read_from<R: AsyncRead + Unpin + Send>(
+        buffer: &mut R,
+        order: ByteOrder,
+    ) -> impl std::future::Future<Output = Result<Self>> + Send;
+
+    /// Reads something from the specified buffer using the given
+    /// [`Settings`](crate::Settings).
+    ///
+    /// Mirrors [`StreamReader::read_with`](crate::StreamReader::read_with).
+    /// The default implementation ignores `settings.max_collection_len` and
+    /// simply delegates to [`read_from`](AsyncStreamReader::read_from) with
+    /// `settings.byte_order`. `String`, `Vec<T>` and `HashMap<T, V>` override
+    /// this so callers can tune the collection length cap per message type
+    /// instead of being stuck with the hardcoded default. Unlike the sync
+    /// side, `settings.length_encoding` is not consulted: the async
+    /// collection impls always read a varint length prefix.
+    fn read_with<R: AsyncRead + Unpin + Send>(
+        buffer: &mut R,
+        settings: Settings,
+    ) -> impl std::future::Future<Output = Result<Self>> + Send {
+        async move { Self::read_from(buffer, settings.byte_order).await }
+    }
+}
+
+/// Allows for asynchronously writing something to a buffer.
+///
+/// This is the async counterpart to [`StreamWriter`](crate::StreamWriter),
+/// built on top of tokio's [`AsyncWrite`].
+pub trait AsyncStreamWriter {
+    /// Writes something to the specified buffer using the given byte order.
+    fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        order: ByteOrder,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+
+    /// Writes something to the specified buffer using the given
+    /// [`Settings`](crate::Settings).
+    ///
+    /// Mirrors [`StreamWriter::write_with`](crate::StreamWriter::write_with).
+    /// The default implementation ignores `settings.max_collection_len` and
+    /// `settings.length_encoding` and simply delegates to
+    /// [`write_to`](AsyncStreamWriter::write_to) with `settings.byte_order`.
+    /// No async impl overrides this, so the async collection impls always
+    /// write a varint length prefix.
+    fn write_with<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        settings: Settings,
+    ) -> impl std::future::Future<Output = Result<()>> + Send {
+        async move { self.write_to(buffer, settings.byte_order).await }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Boolean
+
+impl AsyncStreamReader for bool {
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
+        Ok(buffer.read_u8().await? == 1)
+    }
+}
+
+impl AsyncStreamWriter for bool {
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        _order: ByteOrder,
+    ) -> Result<()> {
+        buffer.write_u8(if *self { 1 } else { 0 }).await?;
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unsigned and signed integers
+
+macro_rules! async_impl_int {
+    ($ty:ty) => {
+        impl AsyncStreamReader for $ty {
+            async fn read_from<R: AsyncRead + Unpin + Send>(
+                buffer: &mut R,
+                order: ByteOrder,
+            ) -> Result<Self> {
+                let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                buffer.read_exact(&mut bytes).await?;
+                Ok(match order.resolve() {
+                    ByteOrder::BigEndian => <$ty>::from_be_bytes(bytes),
+                    ByteOrder::LittleEndian => <$ty>::from_le_bytes(bytes),
+                    ByteOrder::NativeEndian => unreachable!("resolve() never returns NativeEndian"),
+                })
+            }
+        }
+
+        impl AsyncStreamWriter for $ty {
+            async fn write_to<W: AsyncWrite + Unpin + Send>(
+                &self,
+                buffer: &mut W,
+                order: ByteOrder,
+            ) -> Result<()> {
+                let bytes = match order.resolve() {
+                    ByteOrder::BigEndian => self.to_be_bytes(),
+                    ByteOrder::LittleEndian => self.to_le_bytes(),
+                    ByteOrder::NativeEndian => unreachable!("resolve() never returns NativeEndian"),
+                };
+                buffer.write_all(&bytes).await?;
+                Ok(())
+            }
+        }
+    };
+}
+
+async_impl_int!(u64);
+async_impl_int!(u32);
+async_impl_int!(u16);
+async_impl_int!(i64);
+async_impl_int!(i32);
+async_impl_int!(i16);
+
+impl AsyncStreamReader for u8 {
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
+        buffer.read_u8().await
+    }
+}
+
+impl AsyncStreamWriter for u8 {
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        _order: ByteOrder,
+    ) -> Result<()> {
+        buffer.write_u8(*self).await?;
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Signed integers
+
+impl AsyncStreamReader for i8 {
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
+        buffer.read_i8().await
+    }
+}
+
+impl AsyncStreamWriter for i8 {
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        _order: ByteOrder,
+    ) -> Result<()> {
+        buffer.write_i8(*self).await?;
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// String
+
+#[cfg(feature = "batteries-included")]
+impl AsyncStreamReader for String {
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        Self::read_with(buffer, Settings::new(order)).await
+    }
+
+    fn read_with<R: AsyncRead + Unpin + Send>(
+        buffer: &mut R,
+        settings: Settings,
+    ) -> impl std::future::Future<Output = Result<Self>> + Send {
+        async move {
+            let len = read_varint(buffer).await?;
+            check_collection_len(len, settings.max_collection_len)?;
+            let mut bytes = vec![0u8; len as usize];
+            buffer.read_exact(&mut bytes).await?;
+            String::from_utf8(bytes)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+        }
+    }
+}
+
+#[cfg(feature = "batteries-included")]
+impl AsyncStreamWriter for String {
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        order: ByteOrder,
+    ) -> Result<()> {
+        let bytes = self.as_bytes();
+        write_varint(buffer, bytes.len() as u64).await?;
+        buffer.write_all(bytes).await?;
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Collections
+
+#[cfg(feature = "batteries-included")]
+impl<T: AsyncStreamReader + Send> AsyncStreamReader for Vec<T> {
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        Self::read_with(buffer, Settings::new(order)).await
+    }
+
+    fn read_with<R: AsyncRead + Unpin + Send>(
+        buffer: &mut R,
+        settings: Settings,
+    ) -> impl std::future::Future<Output = Result<Self>> + Send {
+        async move {
+            let len = read_varint(buffer).await?;
+            check_collection_len(len, settings.max_collection_len)?;
+            let mut vec = Vec::<T>::with_capacity(len as usize);
+            for _ in 0..len {
+                vec.push(T::read_with(buffer, settings).await?);
+            }
+            Ok(vec)
+        }
+    }
+}
+
+#[cfg(feature = "batteries-included")]
+impl<T: AsyncStreamWriter + Sync> AsyncStreamWriter for Vec<T> {
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        order: ByteOrder,
+    ) -> Result<()> {
+        write_varint(buffer, self.len() as u64).await?;
+        for item in self.iter() {
+            item.write_to(buffer, order).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "batteries-included")]
+impl<
+        T: AsyncStreamReader + Eq + Hash + Send,
+        V: AsyncStreamReader + Send,
+        S: BuildHasher + Default + Send,
+    > AsyncStreamReader for HashMap<T, V, S>
+{
+    async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        Self::read_with(buffer, Settings::new(order)).await
+    }
+
+    fn read_with<R: AsyncRead + Unpin + Send>(
+        buffer: &mut R,
+        settings: Settings,
+    ) -> impl std::future::Future<Output = Result<Self>> + Send {
+        async move {
+            let len = read_varint(buffer).await?;
+            check_collection_len(len, settings.max_collection_len)?;
+            let mut map = HashMap::with_capacity_and_hasher(len as usize, Default::default());
+            for _ in 0..len {
+                let key = T::read_with(buffer, settings).await?;
+                let value = V::read_with(buffer, settings).await?;
+                map.insert(key, value);
+            }
+            Ok(map)
+        }
+    }
+}
+
+#[cfg(feature = "batteries-included")]
+impl<T: AsyncStreamWriter + Sync, V: AsyncStreamWriter + Sync, S: BuildHasher + Sync>
+    AsyncStreamWriter for HashMap<T, V, S>
+{
+    async fn write_to<W: AsyncWrite + Unpin + Send>(
+        &self,
+        buffer: &mut W,
+        order: ByteOrder,
+    ) -> Result<()> {
+        write_varint(buffer, self.len() as u64).await?;
+        for (key, value) in self.iter() {
+            key.write_to(buffer, order).await?;
+            value.write_to(buffer, order).await?;
+        }
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[derive(Debug, PartialEq)]
+    pub struct Foo {
+        pub foo: u32,
+        pub bar: u16,
+    }
+
+    impl AsyncStreamReader for Foo {
+        async fn read_from<R: AsyncRead + Unpin + Send>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+            Ok(Self {
+                foo: u32::read_from(buffer, order).await?,
+                bar: u16::read_from(buffer, order).await?,
+            })
+        }
+    }
+
+    impl AsyncStreamWriter for Foo {
+        async fn write_to<W: AsyncWrite + Unpin + Send>(
+            &self,
+            buffer: &mut W,
+            order: ByteOrder,
+        ) -> Result<()> {
+            self.foo.write_to(buffer, order).await?;
+            self.bar.write_to(buffer, order).await?;
+            Ok(())
+        }
+    }
+
+    async fn serialize<T: AsyncStreamReader + AsyncStreamWriter>(input: T, order: ByteOrder) -> T {
+        let mut buffer = Vec::<u8>::new();
+        input.write_to(&mut buffer, order).await.unwrap();
+
+        let mut cursor = Cursor::new(buffer);
+        T::read_from(&mut cursor, order).await.unwrap()
+    }
+
+    #[tokio::test]
+    pub async fn should_round_trip_custom_struct() {
+        let foo = Foo { foo: 31, bar: 7 };
+        let result = serialize(foo, ByteOrder::BigEndian).await;
+        assert_eq!(Foo { foo: 31, bar: 7 }, result);
+    }
+
+    #[tokio::test]
+    pub async fn sync_and_async_wire_formats_match() {
+        let mut sync_buffer = Vec::<u8>::new();
+        crate::StreamWriter::write_to(&42u32, &mut sync_buffer, ByteOrder::LittleEndian).unwrap();
+
+        let mut cursor = Cursor::new(sync_buffer);
+        let value = u32::read_from(&mut cursor, ByteOrder::LittleEndian)
+            .await
+            .unwrap();
+
+        assert_eq!(42u32, value);
+    }
+
+    #[tokio::test]
+    pub async fn sync_and_async_wire_formats_match_for_collections() {
+        let mut sync_buffer = Vec::<u8>::new();
+        crate::StreamWriter::write_to(
+            &vec!["corgi".to_owned(), "waldo".to_owned()],
+            &mut sync_buffer,
+            ByteOrder::BigEndian,
+        )
+        .unwrap();
+
+        let mut cursor = Cursor::new(sync_buffer);
+        let value = Vec::<String>::read_from(&mut cursor, ByteOrder::BigEndian)
+            .await
+            .unwrap();
+
+        assert_eq!(vec!["corgi".to_owned(), "waldo".to_owned()], value);
+    }
+
+    #[tokio::test]
+    pub async fn a_length_prefix_above_the_configured_maximum_is_rejected() {
+        // Given a length prefix claiming one more element than the
+        // default cap allows.
+        let mut buffer = Vec::<u8>::new();
+        write_varint(&mut buffer, crate::settings::DEFAULT_MAX_COLLECTION_LEN + 1)
+            .await
+            .unwrap();
+
+        // When
+        let mut cursor = Cursor::new(buffer);
+        let result = Vec::<u8>::read_from(&mut cursor, ByteOrder::BigEndian).await;
+
+        // Then
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[tokio::test]
+    pub async fn the_collection_length_cap_can_be_tuned_per_call() {
+        // Given a length prefix that's fine by default but exceeds a
+        // caller-supplied, tighter cap.
+        let mut buffer = Vec::<u8>::new();
+        write_varint(&mut buffer, 17).await.unwrap();
+
+        // When
+        let settings = Settings::default().with_max_collection_len(16);
+        let mut cursor = Cursor::new(buffer);
+        let result = Vec::<u8>::read_with(&mut cursor, settings).await;
+
+        // Then
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[tokio::test]
+    pub async fn invalid_utf8_is_rejected_instead_of_panicking() {
+        // Given a length prefix of 1 followed by a byte that is not valid UTF-8.
+        let buffer = vec![0x01u8, 0xFF];
+        let mut cursor = Cursor::new(buffer);
+
+        // When
+        let result = String::read_from(&mut cursor, ByteOrder::BigEndian).await;
+
+        // Then
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+}
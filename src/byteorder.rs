@@ -1,7 +1,5 @@
 use std::io::{Read, Result, Write};
 
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
-
 use crate::{ByteOrder, StreamReader, StreamWriter};
 
 //////////////////////////////////////////////////////////////////////////////
@@ -9,160 +7,118 @@ use crate::{ByteOrder, StreamReader, StreamWriter};
 
 impl StreamReader for bool {
     fn read_from<R: Read>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
-        Ok(buffer.read_u8()? == 1)
+        let mut byte = [0u8; 1];
+        buffer.read_exact(&mut byte)?;
+        Ok(byte[0] == 1)
     }
 }
 
 impl StreamWriter for bool {
     fn write_to<W: Write>(&self, buffer: &mut W, _order: ByteOrder) -> Result<()> {
-        buffer.write_u8(if *self { 1 } else { 0 })?;
+        buffer.write_all(&[if *self { 1 } else { 0 }])?;
         Ok(())
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////
-// Unsigned integers
-
-impl StreamReader for u64 {
-    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_u64::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_u64::<LittleEndian>()?),
+// Unsigned and signed integers
+//
+// Reads/writes go through fixed-size stack arrays and the primitive's own
+// `to_be_bytes`/`from_be_bytes` (or little-endian counterparts) so this
+// module has no dependency on an external byte-order crate.
+
+macro_rules! impl_int {
+    ($ty:ty) => {
+        impl StreamReader for $ty {
+            fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+                let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                buffer.read_exact(&mut bytes)?;
+                Ok(match order.resolve() {
+                    ByteOrder::BigEndian => <$ty>::from_be_bytes(bytes),
+                    ByteOrder::LittleEndian => <$ty>::from_le_bytes(bytes),
+                    ByteOrder::NativeEndian => unreachable!("resolve() never returns NativeEndian"),
+                })
+            }
         }
-    }
-}
 
-impl StreamWriter for u64 {
-    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_u64::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_u64::<LittleEndian>(*self)?,
+        impl StreamWriter for $ty {
+            fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+                let bytes = match order.resolve() {
+                    ByteOrder::BigEndian => self.to_be_bytes(),
+                    ByteOrder::LittleEndian => self.to_le_bytes(),
+                    ByteOrder::NativeEndian => unreachable!("resolve() never returns NativeEndian"),
+                };
+                buffer.write_all(&bytes)?;
+                Ok(())
+            }
         }
-        Ok(())
-    }
+    };
 }
 
-impl StreamReader for u32 {
-    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_u32::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_u32::<LittleEndian>()?),
-        }
-    }
-}
+impl_int!(u64);
+impl_int!(u32);
+impl_int!(u16);
+impl_int!(i64);
+impl_int!(i32);
+impl_int!(i16);
 
-impl StreamWriter for u32 {
-    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_u32::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_u32::<LittleEndian>(*self)?,
-        }
-        Ok(())
-    }
-}
-
-impl StreamReader for u16 {
-    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_u16::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_u16::<LittleEndian>()?),
-        }
+impl StreamReader for u8 {
+    fn read_from<R: Read>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
+        let mut byte = [0u8; 1];
+        buffer.read_exact(&mut byte)?;
+        Ok(byte[0])
     }
 }
 
-impl StreamWriter for u16 {
-    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_u16::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_u16::<LittleEndian>(*self)?,
-        }
+impl StreamWriter for u8 {
+    fn write_to<W: Write>(&self, buffer: &mut W, _order: ByteOrder) -> Result<()> {
+        buffer.write_all(&[*self])?;
         Ok(())
     }
 }
 
-impl StreamReader for u8 {
+impl StreamReader for i8 {
     fn read_from<R: Read>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
-        Ok(buffer.read_u8()?)
+        let mut byte = [0u8; 1];
+        buffer.read_exact(&mut byte)?;
+        Ok(byte[0] as i8)
     }
 }
 
-impl StreamWriter for u8 {
+impl StreamWriter for i8 {
     fn write_to<W: Write>(&self, buffer: &mut W, _order: ByteOrder) -> Result<()> {
-        buffer.write_u8(*self)?;
+        buffer.write_all(&[*self as u8])?;
         Ok(())
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////
-// Signed integers
+// Floating point
+//
+// Floats are read/written through their bit-pattern integer counterpart, so
+// they share the same stack-array approach as the other primitives.
 
-impl StreamReader for i64 {
+impl StreamReader for f64 {
     fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_i64::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_i64::<LittleEndian>()?),
-        }
+        Ok(f64::from_bits(u64::read_from(buffer, order)?))
     }
 }
 
-impl StreamWriter for i64 {
+impl StreamWriter for f64 {
     fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_i64::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_i64::<LittleEndian>(*self)?,
-        }
-        Ok(())
+        self.to_bits().write_to(buffer, order)
     }
 }
 
-impl StreamReader for i32 {
+impl StreamReader for f32 {
     fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_i32::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_i32::<LittleEndian>()?),
-        }
+        Ok(f32::from_bits(u32::read_from(buffer, order)?))
     }
 }
 
-impl StreamWriter for i32 {
+impl StreamWriter for f32 {
     fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_i32::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_i32::<LittleEndian>(*self)?,
-        }
-        Ok(())
-    }
-}
-
-impl StreamReader for i16 {
-    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-        match order {
-            ByteOrder::BigEndian => Ok(buffer.read_i16::<BigEndian>()?),
-            ByteOrder::LittleEndian => Ok(buffer.read_i16::<LittleEndian>()?),
-        }
-    }
-}
-
-impl StreamWriter for i16 {
-    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-        match order {
-            ByteOrder::BigEndian => buffer.write_i16::<BigEndian>(*self)?,
-            ByteOrder::LittleEndian => buffer.write_i16::<LittleEndian>(*self)?,
-        }
-        Ok(())
-    }
-}
-
-impl StreamReader for i8 {
-    fn read_from<R: Read>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
-        Ok(buffer.read_i8()?)
-    }
-}
-
-impl StreamWriter for i8 {
-    fn write_to<W: Write>(&self, buffer: &mut W, _order: ByteOrder) -> Result<()> {
-        buffer.write_i8(*self)?;
-        Ok(())
+        self.to_bits().write_to(buffer, order)
     }
 }
 
@@ -175,29 +131,6 @@ mod tests {
     use crate::{StreamReader, StreamWriter};
     use std::{fmt::Debug, io::Cursor};
 
-    #[derive(Debug, PartialEq)]
-    pub struct Foo {
-        pub foo: u32,
-        pub bar: u16,
-    }
-
-    impl StreamReader for Foo {
-        fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
-            Ok(Self {
-                foo: u32::read_from(buffer, order)?,
-                bar: u16::read_from(buffer, order)?,
-            })
-        }
-    }
-
-    impl StreamWriter for Foo {
-        fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
-            self.foo.write_to(buffer, order)?;
-            self.bar.write_to(buffer, order)?;
-            Ok(())
-        }
-    }
-
     fn serialize<T: StreamReader + StreamWriter>(input: T, order: ByteOrder) -> T {
         let mut buffer = Vec::<u8>::new();
         input.write_to(&mut buffer, order).unwrap();
@@ -210,22 +143,25 @@ mod tests {
         use super::*;
         use test_case::test_case;
 
-        #[test_case(u8::max_value(), ByteOrder::BigEndian ; "u8 big endian")]
-        #[test_case(u8::max_value(), ByteOrder::LittleEndian ; "u8 little endian")]
-        #[test_case(u16::max_value(), ByteOrder::BigEndian ; "u16 big endian")]
-        #[test_case(u16::max_value(), ByteOrder::LittleEndian ; "u16 little endian")]
-        #[test_case(u32::max_value(), ByteOrder::BigEndian ; "u32 big endian")]
-        #[test_case(u32::max_value(), ByteOrder::LittleEndian ; "u32 little endian")]
-        #[test_case(u64::max_value(), ByteOrder::BigEndian ; "u64 big endian")]
-        #[test_case(u64::max_value(), ByteOrder::LittleEndian ; "u64 little endian")]
-        #[test_case(i8::max_value(), ByteOrder::BigEndian ; "i8 big endian")]
-        #[test_case(i8::max_value(), ByteOrder::LittleEndian ; "i8 little endian")]
-        #[test_case(i16::max_value(), ByteOrder::BigEndian ; "i16 big endian")]
-        #[test_case(i16::max_value(), ByteOrder::LittleEndian ; "i16 little endian")]
-        #[test_case(i32::max_value(), ByteOrder::BigEndian ; "i32 big endian")]
-        #[test_case(i32::max_value(), ByteOrder::LittleEndian ; "i32 little endian")]
-        #[test_case(i64::max_value(), ByteOrder::BigEndian ; "i64 big endian")]
-        #[test_case(i64::max_value(), ByteOrder::LittleEndian ; "i64 little endian")]
+        #[test_case(u8::MAX, ByteOrder::BigEndian ; "u8 big endian")]
+        #[test_case(u8::MAX, ByteOrder::LittleEndian ; "u8 little endian")]
+        #[test_case(u16::MAX, ByteOrder::BigEndian ; "u16 big endian")]
+        #[test_case(u16::MAX, ByteOrder::LittleEndian ; "u16 little endian")]
+        #[test_case(u32::MAX, ByteOrder::BigEndian ; "u32 big endian")]
+        #[test_case(u32::MAX, ByteOrder::LittleEndian ; "u32 little endian")]
+        #[test_case(u32::MAX, ByteOrder::NativeEndian ; "u32 native endian")]
+        #[test_case(u64::MAX, ByteOrder::BigEndian ; "u64 big endian")]
+        #[test_case(u64::MAX, ByteOrder::LittleEndian ; "u64 little endian")]
+        #[test_case(u64::MAX, ByteOrder::NativeEndian ; "u64 native endian")]
+        #[test_case(i8::MAX, ByteOrder::BigEndian ; "i8 big endian")]
+        #[test_case(i8::MAX, ByteOrder::LittleEndian ; "i8 little endian")]
+        #[test_case(i16::MAX, ByteOrder::BigEndian ; "i16 big endian")]
+        #[test_case(i16::MAX, ByteOrder::LittleEndian ; "i16 little endian")]
+        #[test_case(i32::MAX, ByteOrder::BigEndian ; "i32 big endian")]
+        #[test_case(i32::MAX, ByteOrder::LittleEndian ; "i32 little endian")]
+        #[test_case(i32::MAX, ByteOrder::NativeEndian ; "i32 native endian")]
+        #[test_case(i64::MAX, ByteOrder::BigEndian ; "i64 big endian")]
+        #[test_case(i64::MAX, ByteOrder::LittleEndian ; "i64 little endian")]
         pub fn max_value<T: StreamReader + StreamWriter + PartialEq + Debug + Copy + Clone>(
             input: T,
             order: ByteOrder,
@@ -236,22 +172,25 @@ mod tests {
             assert_eq!(input, result);
         }
 
-        #[test_case(u8::min_value(), ByteOrder::BigEndian ; "u8 big endian")]
-        #[test_case(u8::min_value(), ByteOrder::LittleEndian ; "u8 little endian")]
-        #[test_case(u16::min_value(), ByteOrder::BigEndian ; "u16 big endian")]
-        #[test_case(u16::min_value(), ByteOrder::LittleEndian ; "u16 little endian")]
-        #[test_case(u32::min_value(), ByteOrder::BigEndian ; "u32 big endian")]
-        #[test_case(u32::min_value(), ByteOrder::LittleEndian ; "u32 little endian")]
-        #[test_case(u64::min_value(), ByteOrder::BigEndian ; "u64 big endian")]
-        #[test_case(u64::min_value(), ByteOrder::LittleEndian ; "u64 little endian")]
-        #[test_case(i8::min_value(), ByteOrder::BigEndian ; "i8 big endian")]
-        #[test_case(i8::min_value(), ByteOrder::LittleEndian ; "i8 little endian")]
-        #[test_case(i16::min_value(), ByteOrder::BigEndian ; "i16 big endian")]
-        #[test_case(i16::min_value(), ByteOrder::LittleEndian ; "i16 little endian")]
-        #[test_case(i32::min_value(), ByteOrder::BigEndian ; "i32 big endian")]
-        #[test_case(i32::min_value(), ByteOrder::LittleEndian ; "i32 little endian")]
-        #[test_case(i64::min_value(), ByteOrder::BigEndian ; "i64 big endian")]
-        #[test_case(i64::min_value(), ByteOrder::LittleEndian ; "i64 little endian")]
+        #[test_case(u8::MIN, ByteOrder::BigEndian ; "u8 big endian")]
+        #[test_case(u8::MIN, ByteOrder::LittleEndian ; "u8 little endian")]
+        #[test_case(u16::MIN, ByteOrder::BigEndian ; "u16 big endian")]
+        #[test_case(u16::MIN, ByteOrder::LittleEndian ; "u16 little endian")]
+        #[test_case(u32::MIN, ByteOrder::BigEndian ; "u32 big endian")]
+        #[test_case(u32::MIN, ByteOrder::LittleEndian ; "u32 little endian")]
+        #[test_case(u32::MIN, ByteOrder::NativeEndian ; "u32 native endian")]
+        #[test_case(u64::MIN, ByteOrder::BigEndian ; "u64 big endian")]
+        #[test_case(u64::MIN, ByteOrder::LittleEndian ; "u64 little endian")]
+        #[test_case(u64::MIN, ByteOrder::NativeEndian ; "u64 native endian")]
+        #[test_case(i8::MIN, ByteOrder::BigEndian ; "i8 big endian")]
+        #[test_case(i8::MIN, ByteOrder::LittleEndian ; "i8 little endian")]
+        #[test_case(i16::MIN, ByteOrder::BigEndian ; "i16 big endian")]
+        #[test_case(i16::MIN, ByteOrder::LittleEndian ; "i16 little endian")]
+        #[test_case(i32::MIN, ByteOrder::BigEndian ; "i32 big endian")]
+        #[test_case(i32::MIN, ByteOrder::LittleEndian ; "i32 little endian")]
+        #[test_case(i32::MIN, ByteOrder::NativeEndian ; "i32 native endian")]
+        #[test_case(i64::MIN, ByteOrder::BigEndian ; "i64 big endian")]
+        #[test_case(i64::MIN, ByteOrder::LittleEndian ; "i64 little endian")]
         pub fn min_value<T: StreamReader + StreamWriter + PartialEq + Debug + Copy + Clone>(
             input: T,
             order: ByteOrder,
@@ -274,5 +213,75 @@ mod tests {
             // Then
             assert_eq!(input, result);
         }
+
+        #[test_case(f32::MAX, ByteOrder::BigEndian ; "f32 max big endian")]
+        #[test_case(f32::MAX, ByteOrder::LittleEndian ; "f32 max little endian")]
+        #[test_case(f32::MIN, ByteOrder::BigEndian ; "f32 min big endian")]
+        #[test_case(f32::MIN, ByteOrder::LittleEndian ; "f32 min little endian")]
+        #[test_case(0.0f32, ByteOrder::BigEndian ; "f32 zero big endian")]
+        #[test_case(0.0f32, ByteOrder::LittleEndian ; "f32 zero little endian")]
+        #[test_case(f64::MAX, ByteOrder::BigEndian ; "f64 max big endian")]
+        #[test_case(f64::MAX, ByteOrder::LittleEndian ; "f64 max little endian")]
+        #[test_case(f64::MIN, ByteOrder::BigEndian ; "f64 min big endian")]
+        #[test_case(f64::MIN, ByteOrder::LittleEndian ; "f64 min little endian")]
+        #[test_case(0.0f64, ByteOrder::BigEndian ; "f64 zero big endian")]
+        #[test_case(0.0f64, ByteOrder::LittleEndian ; "f64 zero little endian")]
+        #[test_case(0.0f64, ByteOrder::NativeEndian ; "f64 zero native endian")]
+        #[test_case(f32::INFINITY, ByteOrder::BigEndian ; "f32 infinity big endian")]
+        #[test_case(f32::INFINITY, ByteOrder::LittleEndian ; "f32 infinity little endian")]
+        #[test_case(f32::NEG_INFINITY, ByteOrder::BigEndian ; "f32 neg infinity big endian")]
+        #[test_case(f32::NEG_INFINITY, ByteOrder::LittleEndian ; "f32 neg infinity little endian")]
+        #[test_case(f64::INFINITY, ByteOrder::BigEndian ; "f64 infinity big endian")]
+        #[test_case(f64::INFINITY, ByteOrder::LittleEndian ; "f64 infinity little endian")]
+        #[test_case(f64::NEG_INFINITY, ByteOrder::BigEndian ; "f64 neg infinity big endian")]
+        #[test_case(f64::NEG_INFINITY, ByteOrder::LittleEndian ; "f64 neg infinity little endian")]
+        pub fn float<T: StreamReader + StreamWriter + PartialEq + Debug + Copy + Clone>(
+            input: T,
+            order: ByteOrder,
+        ) {
+            // Given, When
+            let result = serialize(input, order);
+            // Then
+            assert_eq!(input, result);
+        }
+
+        // NaN != NaN, so we compare the raw bits of the round-tripped value instead.
+        #[test_case(f32::NAN.to_bits(), ByteOrder::BigEndian ; "f32 nan big endian")]
+        #[test_case(f32::NAN.to_bits(), ByteOrder::LittleEndian ; "f32 nan little endian")]
+        pub fn float_nan_f32(bits: u32, order: ByteOrder) {
+            // Given, When
+            let result = serialize(f32::from_bits(bits), order);
+            // Then
+            assert_eq!(bits, result.to_bits());
+        }
+
+        #[test_case(f64::NAN.to_bits(), ByteOrder::BigEndian ; "f64 nan big endian")]
+        #[test_case(f64::NAN.to_bits(), ByteOrder::LittleEndian ; "f64 nan little endian")]
+        pub fn float_nan_f64(bits: u64, order: ByteOrder) {
+            // Given, When
+            let result = serialize(f64::from_bits(bits), order);
+            // Then
+            assert_eq!(bits, result.to_bits());
+        }
+
+        // 0.0 == -0.0, so compare the raw bits of the round-tripped value
+        // instead to make sure the sign bit survives the trip.
+        #[test_case((-0.0f32).to_bits(), ByteOrder::BigEndian ; "f32 neg zero big endian")]
+        #[test_case((-0.0f32).to_bits(), ByteOrder::LittleEndian ; "f32 neg zero little endian")]
+        pub fn float_neg_zero_f32(bits: u32, order: ByteOrder) {
+            // Given, When
+            let result = serialize(f32::from_bits(bits), order);
+            // Then
+            assert_eq!(bits, result.to_bits());
+        }
+
+        #[test_case((-0.0f64).to_bits(), ByteOrder::BigEndian ; "f64 neg zero big endian")]
+        #[test_case((-0.0f64).to_bits(), ByteOrder::LittleEndian ; "f64 neg zero little endian")]
+        pub fn float_neg_zero_f64(bits: u64, order: ByteOrder) {
+            // Given, When
+            let result = serialize(f64::from_bits(bits), order);
+            // Then
+            assert_eq!(bits, result.to_bits());
+        }
     }
 }
@@ -0,0 +1,132 @@
+use std::io::{Read, Result, Write};
+
+use crate::{ByteOrder, StreamReader, StreamWriter};
+
+//////////////////////////////////////////////////////////////////////////////
+// Arrays
+
+impl<T: StreamReader, const N: usize> StreamReader for [T; N] {
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        let mut first_err = None;
+        let array: [Option<T>; N] = core::array::from_fn(|_| {
+            if first_err.is_some() {
+                return None;
+            }
+            match T::read_from(buffer, order) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    first_err = Some(err);
+                    None
+                }
+            }
+        });
+
+        if let Some(err) = first_err {
+            return Err(err);
+        }
+
+        // `first_err` is `None`, so every element was read successfully.
+        Ok(array.map(Option::unwrap))
+    }
+}
+
+impl<T: StreamWriter, const N: usize> StreamWriter for [T; N] {
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+        for item in self.iter() {
+            item.write_to(buffer, order)?;
+        }
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Tuples
+
+macro_rules! impl_tuple {
+    ($($idx:tt => $name:ident),+) => {
+        impl<$($name: StreamReader),+> StreamReader for ($($name,)+) {
+            fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+                Ok(($($name::read_from(buffer, order)?,)+))
+            }
+        }
+
+        impl<$($name: StreamWriter),+> StreamWriter for ($($name,)+) {
+            fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+                $(self.$idx.write_to(buffer, order)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple!(0 => A);
+impl_tuple!(0 => A, 1 => B);
+impl_tuple!(0 => A, 1 => B, 2 => C);
+impl_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+
+//////////////////////////////////////////////////////////////////////////////
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use test_case::test_case;
+
+    fn serialize<T: StreamReader + StreamWriter>(input: T, order: ByteOrder) -> T {
+        let mut buffer = Vec::<u8>::new();
+        input.write_to(&mut buffer, order).unwrap();
+
+        let mut cursor = Cursor::new(buffer);
+        T::read_from(&mut cursor, order).unwrap()
+    }
+
+    #[test_case(ByteOrder::BigEndian ; "big endian")]
+    #[test_case(ByteOrder::LittleEndian ; "little endian")]
+    pub fn array(order: ByteOrder) {
+        // Given
+        let input = [1u32, 2, 3, 4];
+        // When
+        let result = serialize(input, order);
+        // Then
+        assert_eq!(input, result);
+    }
+
+    #[test_case(ByteOrder::BigEndian ; "big endian")]
+    #[test_case(ByteOrder::LittleEndian ; "little endian")]
+    pub fn array_of_floats(order: ByteOrder) {
+        // Given
+        let input = [f64::INFINITY, f64::NEG_INFINITY, 0.0, 1.5];
+        // When
+        let result = serialize(input, order);
+        // Then
+        assert_eq!(input, result);
+    }
+
+    #[test_case(ByteOrder::BigEndian ; "big endian")]
+    #[test_case(ByteOrder::LittleEndian ; "little endian")]
+    pub fn array_has_no_length_prefix(order: ByteOrder) {
+        // Given
+        let input = [1u8, 2, 3, 4];
+        // When
+        let mut buffer = Vec::<u8>::new();
+        input.write_to(&mut buffer, order).unwrap();
+        // Then
+        assert_eq!(vec![1u8, 2, 3, 4], buffer);
+    }
+
+    #[test_case(ByteOrder::BigEndian ; "big endian")]
+    #[test_case(ByteOrder::LittleEndian ; "little endian")]
+    pub fn tuple(order: ByteOrder) {
+        // Given
+        let input = (31u32, "corgi".to_owned());
+        // When
+        let result = serialize(input.clone(), order);
+        // Then
+        assert_eq!(input, result);
+    }
+}
@@ -3,14 +3,15 @@ This crate provides a convenient way of reading and writing bytes to a buffer
 that implements the standard [`Read`] or [`Write`] traits.
 
 Supported std types include [`u8`], [`u16`], [`u32`], [`u64`], [`i8`],
-[`i16`], [`i32`], [`i64`], [`String`], [`Vec<T>`] and [`HashMap<T, V>`].
+[`i16`], [`i32`], [`i64`], [`f32`], [`f64`], [`String`], [`Vec<T>`] and
+[`HashMap<T, V>`].
 
-Reading and writing of these types is done using the [`byteorder`]
-crate as big endian.
-The reason for reading and writing as big endian is that this crate was
-written with sending data over the network in mind. It should be fairly
-easy to add support for little endian if anyone would have use for it,
-but for now it's big endian only.
+Reading and writing of primitives is done using the byte order picked by
+passing a [`ByteOrder`] to every call, implemented internally with no
+external byte-order dependency. Besides the concrete
+[`ByteOrder::BigEndian`] and [`ByteOrder::LittleEndian`] orders, there's
+also [`ByteOrder::NativeEndian`] for callers that just want to match
+whatever the host machine uses.
 
 # Installation
 
@@ -25,7 +26,7 @@ bytestream = "0.*"
 
 ```rust
 use std::io::{Cursor, Read, Result, Write};
-use bytestream::Streamable;
+use bytestream::{ByteOrder, StreamReader, StreamWriter};
 
 #[derive(Debug, PartialEq)]
 pub struct Foo {
@@ -33,17 +34,19 @@ pub struct Foo {
     baz: u32,
 }
 
-impl Streamable for Foo {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
+impl StreamReader for Foo {
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
         Ok(Self {
-            bar: String::read_from(buffer)?,
-            baz: u32::read_from(buffer)?,
+            bar: String::read_from(buffer, order)?,
+            baz: u32::read_from(buffer, order)?,
         })
     }
+}
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        self.bar.write_to(buffer)?;
-        self.baz.write_to(buffer)?;
+impl StreamWriter for Foo {
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+        self.bar.write_to(buffer, order)?;
+        self.baz.write_to(buffer, order)?;
         Ok(())
     }
 }
@@ -53,12 +56,12 @@ let mut buffer = Vec::<u8>::new();
 
 // Write some data to the buffer
 let foo = Foo { bar: "corgi".to_owned(), baz: 37 };
-foo.write_to(&mut buffer).unwrap();
+foo.write_to(&mut buffer, ByteOrder::BigEndian).unwrap();
 
 // Read the data back from the buffer
 // We wrap the buffer in a Cursor::<T> that implements the `Read` trait
 let mut cursor = Cursor::new(buffer);
-let other = Foo::read_from(&mut cursor).unwrap();
+let other = Foo::read_from(&mut cursor, ByteOrder::BigEndian).unwrap();
 
 assert_eq!(foo, other);
 ```
@@ -80,7 +83,6 @@ The inspiration from this crate came from the [`Stevenarella`] Minecraft client.
 
 [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
 [`Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
-[`byteorder`]: https://github.com/BurntSushi/byteorder
 [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
 [`u16`]: https://doc.rust-lang.org/std/primitive.u16.html
 [`u32`]: https://doc.rust-lang.org/std/primitive.u32.html
@@ -89,6 +91,8 @@ The inspiration from this crate came from the [`Stevenarella`] Minecraft client.
 [`i16`]: https://doc.rust-lang.org/std/primitive.i16.html
 [`i32`]: https://doc.rust-lang.org/std/primitive.i32.html
 [`i64`]: https://doc.rust-lang.org/std/primitive.i64.html
+[`f32`]: https://doc.rust-lang.org/std/primitive.f32.html
+[`f64`]: https://doc.rust-lang.org/std/primitive.f64.html
 [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
 [`Vec<T>`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 [`HashMap<T, V>`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
@@ -102,179 +106,240 @@ The inspiration from this crate came from the [`Stevenarella`] Minecraft client.
 use std::collections::HashMap;
 #[cfg(feature = "batteries-included")]
 use std::hash::{BuildHasher, Hash};
-use std::io::{Read, Result, Write};
+use std::io::{Cursor, Read, Result, Write};
 
 #[cfg(feature = "batteries-included")]
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+mod byteorder;
+
+mod varint;
+pub use varint::{read_varint, write_varint, VarInt};
+
+mod fixed_size;
+
+mod settings;
+pub use settings::{LengthEncoding, Settings};
 
-/// The streamable trait allows for reading and writing
-/// bytes to and from a buffer.
+/// Async counterparts to [`StreamReader`]/[`StreamWriter`], built on top of
+/// tokio's [`AsyncRead`](tokio::io::AsyncRead)/[`AsyncWrite`](tokio::io::AsyncWrite).
+///
+/// Enabled via the `tokio` feature flag.
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "tokio")]
+pub use async_io::{AsyncStreamReader, AsyncStreamWriter};
+
+/// The byte order to use when reading or writing a value.
+///
+/// [`ByteOrder::NativeEndian`] is not a concrete order in itself; it is
+/// resolved to [`ByteOrder::BigEndian`] or [`ByteOrder::LittleEndian`] via
+/// [`ByteOrder::resolve`] depending on the target the crate is compiled
+/// for. Two [`ByteOrder`] values are considered equal if they resolve to
+/// the same concrete order, so `ByteOrder::NativeEndian` compares equal to
+/// whichever of the two it resolves to on the current target.
+#[derive(Debug, Clone, Copy)]
+pub enum ByteOrder {
+    /// Most significant byte first.
+    BigEndian,
+    /// Least significant byte first.
+    LittleEndian,
+    /// Whatever order the target machine uses natively.
+    NativeEndian,
+}
+
+impl ByteOrder {
+    /// Resolves [`ByteOrder::NativeEndian`] to a concrete byte order,
+    /// returning [`ByteOrder::BigEndian`] or [`ByteOrder::LittleEndian`]
+    /// unchanged.
+    pub fn resolve(self) -> ByteOrder {
+        match self {
+            ByteOrder::NativeEndian => {
+                #[cfg(target_endian = "little")]
+                {
+                    ByteOrder::LittleEndian
+                }
+                #[cfg(target_endian = "big")]
+                {
+                    ByteOrder::BigEndian
+                }
+            }
+            order => order,
+        }
+    }
+}
+
+impl PartialEq for ByteOrder {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self.resolve(), other.resolve()),
+            (ByteOrder::BigEndian, ByteOrder::BigEndian)
+                | (ByteOrder::LittleEndian, ByteOrder::LittleEndian)
+        )
+    }
+}
+
+impl Eq for ByteOrder {}
+
+/// Allows for reading something from a buffer.
 ///
 /// # Example
 ///
 /// ```
-/// use std::io::{Read, Result, Write};
-/// use bytestream::Streamable;
+/// use std::io::{Read, Result};
+/// use bytestream::{ByteOrder, StreamReader};
 ///
 /// pub struct Foo {
-///     bar: String,
-///     baz: u32,
+///     bar: u32,
 /// }
 ///
-/// impl Streamable for Foo {
-///     fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
+/// impl StreamReader for Foo {
+///     fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
 ///         Ok(Self {
-///             bar: String::read_from(buffer)?,
-///             baz: u32::read_from(buffer)?,
+///             bar: u32::read_from(buffer, order)?,
 ///         })
 ///     }
-///     fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-///         self.bar.write_to(buffer)?;
-///         self.baz.write_to(buffer)?;
-///         Ok(())
-///     }
 /// }
 /// ```
-pub trait Streamable: Sized {
-    /// Reads something from the specified buffer.
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self>;
-
-    /// Writes something to the specified buffer.
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()>;
-}
-
-//////////////////////////////////////////////////////////////////////////////
-// Boolean
-
-#[cfg(feature = "batteries-included")]
-impl Streamable for bool {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_u8()? == 1)
+pub trait StreamReader: Sized {
+    /// Reads something from the specified buffer using the given byte order.
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self>;
+
+    /// Reads something from the specified buffer using the given [`Settings`].
+    ///
+    /// The default implementation ignores `settings.length_encoding` and
+    /// simply delegates to [`read_from`](StreamReader::read_from) with
+    /// `settings.byte_order`. Types whose wire format depends on more than
+    /// the byte order (for example the length-prefixed collection types)
+    /// override this instead.
+    fn read_with<R: Read>(buffer: &mut R, settings: Settings) -> Result<Self> {
+        Self::read_from(buffer, settings.byte_order)
     }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u8(if *self { 1 } else { 0 })?;
-        Ok(())
+    /// Reads something from the front of `bytes` using the given byte order,
+    /// returning the value together with the number of bytes consumed.
+    ///
+    /// This is a convenience for callers that have a plain `&[u8]` slice
+    /// rather than a [`Read`] buffer, for example when picking a value out
+    /// of a fixed-layout header.
+    fn from_bytes(bytes: &[u8], order: ByteOrder) -> Result<(Self, usize)> {
+        let mut cursor = Cursor::new(bytes);
+        let value = Self::read_from(&mut cursor, order)?;
+        Ok((value, cursor.position() as usize))
     }
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Unsigned integers
-
-#[cfg(feature = "batteries-included")]
-impl Streamable for u64 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_u64::<BigEndian>()?)
+/// Allows for writing something to a buffer.
+///
+/// # Example
+///
+/// ```
+/// use std::io::{Result, Write};
+/// use bytestream::{ByteOrder, StreamWriter};
+///
+/// pub struct Foo {
+///     bar: u32,
+/// }
+///
+/// impl StreamWriter for Foo {
+///     fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+///         self.bar.write_to(buffer, order)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait StreamWriter {
+    /// Writes something to the specified buffer using the given byte order.
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()>;
+
+    /// Writes something to the specified buffer using the given [`Settings`].
+    ///
+    /// The default implementation ignores `settings.length_encoding` and
+    /// simply delegates to [`write_to`](StreamWriter::write_to) with
+    /// `settings.byte_order`. Types whose wire format depends on more than
+    /// the byte order (for example the length-prefixed collection types)
+    /// override this instead.
+    fn write_with<W: Write>(&self, buffer: &mut W, settings: Settings) -> Result<()> {
+        self.write_to(buffer, settings.byte_order)
     }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u64::<BigEndian>(*self)?;
-        Ok(())
+    /// Writes something to the front of `bytes` using the given byte order,
+    /// returning the number of bytes produced.
+    ///
+    /// This is a convenience for callers that have a plain `&mut [u8]`
+    /// slice rather than a [`Write`] buffer, for example when filling in a
+    /// fixed-layout header. It avoids the `Vec`/[`Cursor`] round-trip for
+    /// small primitives and fixed-layout structs.
+    fn to_bytes(&self, bytes: &mut [u8], order: ByteOrder) -> Result<usize> {
+        let mut cursor = Cursor::new(bytes);
+        self.write_to(&mut cursor, order)?;
+        Ok(cursor.position() as usize)
     }
 }
 
-#[cfg(feature = "batteries-included")]
-impl Streamable for u32 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_u32::<BigEndian>()?)
-    }
-
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u32::<BigEndian>(*self)?;
-        Ok(())
-    }
-}
+//////////////////////////////////////////////////////////////////////////////
+// Collection length prefixes
 
+/// Reads a collection length prefix using the encoding picked by `settings`,
+/// rejecting lengths beyond `settings.max_collection_len` so a hostile
+/// length prefix can't be used to trigger an oversized `with_capacity`
+/// allocation before a single element has actually been read.
 #[cfg(feature = "batteries-included")]
-impl Streamable for u16 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_u16::<BigEndian>()?)
+fn read_len<R: Read>(buffer: &mut R, settings: Settings) -> Result<u64> {
+    let len = match settings.length_encoding {
+        LengthEncoding::Fixed16 => <u16 as StreamReader>::read_from(buffer, settings.byte_order)? as u64,
+        LengthEncoding::Fixed32 => <u32 as StreamReader>::read_from(buffer, settings.byte_order)? as u64,
+        LengthEncoding::Varint => read_varint(buffer)?,
+    };
+
+    if len > settings.max_collection_len {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "collection length {len} exceeds the configured maximum of {}",
+                settings.max_collection_len
+            ),
+        ));
     }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u16::<BigEndian>(*self)?;
-        Ok(())
-    }
+    Ok(len)
 }
 
+/// Writes a collection length prefix using the encoding picked by `settings`.
 #[cfg(feature = "batteries-included")]
-impl Streamable for u8 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_u8()?)
-    }
-
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u8(*self)?;
-        Ok(())
+fn write_len<W: Write>(buffer: &mut W, settings: Settings, len: u64) -> Result<()> {
+    match settings.length_encoding {
+        LengthEncoding::Fixed16 => StreamWriter::write_to(&(len as u16), buffer, settings.byte_order),
+        LengthEncoding::Fixed32 => StreamWriter::write_to(&(len as u32), buffer, settings.byte_order),
+        LengthEncoding::Varint => write_varint(buffer, len),
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////
-// Signed integers
-
-#[cfg(feature = "batteries-included")]
-impl Streamable for i64 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_i64::<BigEndian>()?)
-    }
-
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_i64::<BigEndian>(*self)?;
-        Ok(())
-    }
-}
-
-#[cfg(feature = "batteries-included")]
-impl Streamable for i32 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_i32::<BigEndian>()?)
-    }
-
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_i32::<BigEndian>(*self)?;
-        Ok(())
-    }
-}
+// String
 
 #[cfg(feature = "batteries-included")]
-impl Streamable for i16 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_i16::<BigEndian>()?)
+impl StreamReader for String {
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        <String as StreamReader>::read_with(buffer, Settings::new(order))
     }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_i16::<BigEndian>(*self)?;
-        Ok(())
+    fn read_with<R: Read>(buffer: &mut R, settings: Settings) -> Result<Self> {
+        let len = read_len(buffer, settings)?;
+        let mut bytes = Vec::<u8>::new();
+        buffer.take(len).read_to_end(&mut bytes)?;
+        String::from_utf8(bytes)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
     }
 }
 
 #[cfg(feature = "batteries-included")]
-impl Streamable for i8 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        Ok(buffer.read_i8()?)
+impl StreamWriter for String {
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+        StreamWriter::write_with(self, buffer, Settings::new(order))
     }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_i8(*self)?;
-        Ok(())
-    }
-}
-
-//////////////////////////////////////////////////////////////////////////////
-// String
-
-#[cfg(feature = "batteries-included")]
-impl Streamable for String {
-    fn read_from<R: std::io::Read>(buffer: &mut R) -> Result<Self> {
-        let len = u16::read_from(buffer)?; // TODO: Use 7-bit encoded size
-        let mut bytes = Vec::<u8>::new();
-        buffer.take(len as u64).read_to_end(&mut bytes)?;
-        let ret = String::from_utf8(bytes).unwrap();
-        Ok(ret)
-    }
-    fn write_to<W: std::io::Write>(&self, buffer: &mut W) -> Result<()> {
+    fn write_with<W: Write>(&self, buffer: &mut W, settings: Settings) -> Result<()> {
         let bytes = self.as_bytes();
-        (bytes.len() as u16).write_to(buffer)?; // TODO: Use 7-bit encoded size
+        write_len(buffer, settings, bytes.len() as u64)?;
         buffer.write_all(bytes)?;
         Ok(())
     }
@@ -284,43 +349,67 @@ impl Streamable for String {
 // Collections
 
 #[cfg(feature = "batteries-included")]
-impl<T: Streamable> Streamable for Vec<T> {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        let count = buffer.read_u16::<BigEndian>()?; // TODO: Use 7-bit encoded size
-        let mut vec = Vec::<T>::with_capacity(count as usize);
-        for _ in 0..count {
-            vec.push(T::read_from(buffer)?);
+impl<T: StreamReader> StreamReader for Vec<T> {
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        <Vec<T> as StreamReader>::read_with(buffer, Settings::new(order))
+    }
+
+    fn read_with<R: Read>(buffer: &mut R, settings: Settings) -> Result<Self> {
+        let len = read_len(buffer, settings)?;
+        let mut vec = Vec::<T>::with_capacity(len as usize);
+        for _ in 0..len {
+            vec.push(T::read_with(buffer, settings)?);
         }
         Ok(vec)
     }
+}
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        buffer.write_u16::<BigEndian>(self.len() as u16)?; // TODO: Use 7-bit encoded size
+#[cfg(feature = "batteries-included")]
+impl<T: StreamWriter> StreamWriter for Vec<T> {
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+        StreamWriter::write_with(self, buffer, Settings::new(order))
+    }
+
+    fn write_with<W: Write>(&self, buffer: &mut W, settings: Settings) -> Result<()> {
+        write_len(buffer, settings, self.len() as u64)?;
         for item in self.iter() {
-            item.write_to(buffer)?;
+            item.write_with(buffer, settings)?;
         }
         Ok(())
     }
 }
 
 #[cfg(feature = "batteries-included")]
-impl<T: Streamable + Eq + Hash, V: Streamable, S: BuildHasher + Default> Streamable
+impl<T: StreamReader + Eq + Hash, V: StreamReader, S: BuildHasher + Default> StreamReader
     for HashMap<T, V, S>
 {
-    fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
-        let len = u32::read_from(buffer)?; // TODO: Use 7-bit encoded size
+    fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
+        <HashMap<T, V, S> as StreamReader>::read_with(buffer, Settings::new(order))
+    }
+
+    fn read_with<R: Read>(buffer: &mut R, settings: Settings) -> Result<Self> {
+        let len = read_len(buffer, settings)?;
         let mut map = HashMap::with_capacity_and_hasher(len as usize, Default::default());
         for _ in 0..len {
-            map.insert(T::read_from(buffer)?, V::read_from(buffer)?);
+            let key = T::read_with(buffer, settings)?;
+            let value = V::read_with(buffer, settings)?;
+            map.insert(key, value);
         }
         Ok(map)
     }
+}
+
+#[cfg(feature = "batteries-included")]
+impl<T: StreamWriter, V: StreamWriter, S: BuildHasher> StreamWriter for HashMap<T, V, S> {
+    fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+        StreamWriter::write_with(self, buffer, Settings::new(order))
+    }
 
-    fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-        (self.len() as u32).write_to(buffer)?; // TODO: Use 7-bit encoded size
+    fn write_with<W: Write>(&self, buffer: &mut W, settings: Settings) -> Result<()> {
+        write_len(buffer, settings, self.len() as u64)?;
         for (key, value) in self.iter() {
-            key.write_to(buffer)?;
-            value.write_to(buffer)?;
+            key.write_with(buffer, settings)?;
+            value.write_with(buffer, settings)?;
         }
         Ok(())
     }
@@ -332,7 +421,7 @@ impl<T: Streamable + Eq + Hash, V: Streamable, S: BuildHasher + Default> Streama
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::Cursor;
+    use std::{fmt::Debug, io::Cursor};
 
     // https://stackoverflow.com/a/27582993/936
     macro_rules! map(
@@ -354,6 +443,7 @@ mod tests {
         pub baz: Baz,
         pub corgi: Vec<u8>,
         pub waldo: HashMap<i32, String>,
+        pub quux: String,
     }
 
     #[derive(Debug, PartialEq)]
@@ -361,39 +451,54 @@ mod tests {
         pub baz: u32,
     }
 
-    impl Streamable for Foo {
-        fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
+    impl StreamReader for Foo {
+        fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
             Ok(Self {
-                foo: u32::read_from(buffer)?,
-                bar: u16::read_from(buffer)?,
-                baz: Baz::read_from(buffer)?,
-                corgi: Vec::<u8>::read_from(buffer)?,
-                waldo: HashMap::<i32, String>::read_from(buffer)?,
+                foo: <u32 as StreamReader>::read_from(buffer, order)?,
+                bar: <u16 as StreamReader>::read_from(buffer, order)?,
+                baz: Baz::read_from(buffer, order)?,
+                corgi: <Vec<u8> as StreamReader>::read_from(buffer, order)?,
+                waldo: <HashMap<i32, String> as StreamReader>::read_from(buffer, order)?,
+                quux: <String as StreamReader>::read_from(buffer, order)?,
             })
         }
+    }
 
-        fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-            self.foo.write_to(buffer)?;
-            self.bar.write_to(buffer)?;
-            self.baz.write_to(buffer)?;
-            self.corgi.write_to(buffer)?;
-            self.waldo.write_to(buffer)?;
+    impl StreamWriter for Foo {
+        fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+            StreamWriter::write_to(&self.foo, buffer, order)?;
+            StreamWriter::write_to(&self.bar, buffer, order)?;
+            self.baz.write_to(buffer, order)?;
+            StreamWriter::write_to(&self.corgi, buffer, order)?;
+            StreamWriter::write_to(&self.waldo, buffer, order)?;
+            StreamWriter::write_to(&self.quux, buffer, order)?;
             Ok(())
         }
     }
 
-    impl Streamable for Baz {
-        fn read_from<R: Read>(buffer: &mut R) -> Result<Self> {
+    impl StreamReader for Baz {
+        fn read_from<R: Read>(buffer: &mut R, order: ByteOrder) -> Result<Self> {
             Ok(Self {
-                baz: u32::read_from(buffer)?,
+                baz: <u32 as StreamReader>::read_from(buffer, order)?,
             })
         }
-        fn write_to<W: Write>(&self, buffer: &mut W) -> Result<()> {
-            self.baz.write_to(buffer)?;
+    }
+
+    impl StreamWriter for Baz {
+        fn write_to<W: Write>(&self, buffer: &mut W, order: ByteOrder) -> Result<()> {
+            StreamWriter::write_to(&self.baz, buffer, order)?;
             Ok(())
         }
     }
 
+    fn serialize<T: StreamReader + StreamWriter>(input: T, order: ByteOrder) -> T {
+        let mut buffer = Vec::<u8>::new();
+        input.write_to(&mut buffer, order).unwrap();
+
+        let mut cursor = Cursor::new(buffer);
+        T::read_from(&mut cursor, order).unwrap()
+    }
+
     #[test]
     pub fn should_serialize_custom_struct() {
         let foo = Foo {
@@ -402,14 +507,160 @@ mod tests {
             baz: Baz { baz: 23 },
             corgi: vec![1, 2, 3, 4],
             waldo: map! { 1 => "A".to_owned(), 2 => "B".to_owned() },
+            quux: "foo".to_owned(),
         };
 
+        let result = serialize(foo, ByteOrder::BigEndian);
+        assert_eq!(
+            Foo {
+                foo: 31,
+                bar: 7,
+                baz: Baz { baz: 23 },
+                corgi: vec![1, 2, 3, 4],
+                waldo: map! { 1 => "A".to_owned(), 2 => "B".to_owned() },
+                quux: "foo".to_owned(),
+            },
+            result
+        );
+    }
+
+    #[test]
+    pub fn native_endian_resolves_to_a_concrete_order() {
+        let resolved = ByteOrder::NativeEndian.resolve();
+        assert!(resolved == ByteOrder::BigEndian || resolved == ByteOrder::LittleEndian);
+        assert_eq!(ByteOrder::NativeEndian, resolved);
+    }
+
+    #[test]
+    pub fn settings_length_encoding_controls_the_prefix_width() {
+        let input = vec![1u8, 2, 3];
+
+        let mut varint_buffer = Vec::<u8>::new();
+        input
+            .write_with(&mut varint_buffer, Settings::default())
+            .unwrap();
+        // A Varint length prefix for 3 elements is a single byte.
+        assert_eq!(vec![3u8, 1, 2, 3], varint_buffer);
+
+        let mut fixed16_buffer = Vec::<u8>::new();
+        let settings = Settings::default().with_length_encoding(LengthEncoding::Fixed16);
+        input.write_with(&mut fixed16_buffer, settings).unwrap();
+        // A Fixed16 length prefix for 3 elements is two bytes.
+        assert_eq!(vec![0u8, 3, 1, 2, 3], fixed16_buffer);
+
+        let mut cursor = Cursor::new(fixed16_buffer);
+        let result = Vec::<u8>::read_with(&mut cursor, settings).unwrap();
+        assert_eq!(input, result);
+    }
+
+    #[test]
+    pub fn settings_apply_to_nested_collections_too() {
+        // Given a `Vec<String>`, so both the outer `Vec` length and each
+        // inner `String` length are collection prefixes.
+        let input = vec!["corgi".to_owned(), "waldo".to_owned()];
+        let settings = Settings::default().with_length_encoding(LengthEncoding::Fixed16);
+
+        // When
+        let mut buffer = Vec::<u8>::new();
+        input.write_with(&mut buffer, settings).unwrap();
+
+        // Then every length prefix, not just the outer one, is Fixed16.
+        assert_eq!(
+            vec![0u8, 2, 0, 5, b'c', b'o', b'r', b'g', b'i', 0, 5, b'w', b'a', b'l', b'd', b'o'],
+            buffer
+        );
+
+        let mut cursor = Cursor::new(buffer);
+        let result = Vec::<String>::read_with(&mut cursor, settings).unwrap();
+        assert_eq!(input, result);
+    }
+
+    #[test]
+    pub fn to_bytes_and_from_bytes_round_trip_without_a_cursor() {
+        // Given
+        let mut bytes = [0u8; 4];
+        // When
+        let written = 31u32.to_bytes(&mut bytes, ByteOrder::BigEndian).unwrap();
+        let (result, read) = u32::from_bytes(&bytes, ByteOrder::BigEndian).unwrap();
+        // Then
+        assert_eq!(4, written);
+        assert_eq!(4, read);
+        assert_eq!(31u32, result);
+    }
+
+    #[test]
+    pub fn a_length_prefix_above_the_configured_maximum_is_rejected() {
+        // Given a length prefix claiming far more elements than are
+        // actually available, to guard against a hostile peer triggering
+        // an oversized `with_capacity` allocation.
         let mut buffer = Vec::<u8>::new();
-        foo.write_to(&mut buffer).unwrap();
+        write_varint(&mut buffer, 1_000_000).unwrap();
+
+        let settings = Settings::default().with_max_collection_len(16);
+        let mut cursor = Cursor::new(buffer);
 
+        // When
+        let result = Vec::<u8>::read_with(&mut cursor, settings);
+
+        // Then
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[test]
+    pub fn invalid_utf8_is_rejected_instead_of_panicking() {
+        // Given a length prefix of 1 followed by a byte that is not valid UTF-8.
+        let buffer = vec![0x01u8, 0xFF];
         let mut cursor = Cursor::new(buffer);
-        let result = Foo::read_from(&mut cursor).unwrap();
 
-        assert_eq!(foo, result);
+        // When
+        let result = String::read_from(&mut cursor, ByteOrder::BigEndian);
+
+        // Then
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+
+    mod std_types {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("Hello World".to_owned(), ByteOrder::BigEndian ; "big endian")]
+        #[test_case("Hello World".to_owned(), ByteOrder::LittleEndian ; "little endian")]
+        pub fn string(input: String, order: ByteOrder) {
+            // Given, When
+            let result = serialize(input.clone(), order);
+            // Then
+            assert_eq!(input, result);
+        }
+
+        #[test_case(vec![0u8, 1u8, 2u8, 3u8, 4u8], ByteOrder::BigEndian ; "u8 big endian")]
+        #[test_case(vec![0u8, 1u8, 2u8, 3u8, 4u8], ByteOrder::LittleEndian ; "u8 little endian")]
+        #[test_case(vec!["A".to_owned(), "B".to_owned()], ByteOrder::BigEndian ; "String big endian")]
+        #[test_case(vec!["A".to_owned(), "B".to_owned()], ByteOrder::LittleEndian ; "String little endian")]
+        pub fn vec<T: StreamReader + StreamWriter + PartialEq + Debug + Clone>(
+            input: Vec<T>,
+            order: ByteOrder,
+        ) {
+            // Given, When
+            let result = serialize(input.clone(), order);
+            // Then
+            assert_eq!(input, result);
+        }
+
+        #[test_case(ByteOrder::BigEndian ; "big endian")]
+        #[test_case(ByteOrder::LittleEndian ; "little endian")]
+        pub fn map(order: ByteOrder) {
+            // Given
+            let input = map! {
+                1 => "A".to_owned(),
+                2 => "B".to_owned(),
+                3 => "C".to_owned()
+            };
+            // When
+            let result = serialize(input.clone(), order);
+            // Then
+            assert_eq!(input, result);
+        }
     }
 }
@@ -0,0 +1,109 @@
+use crate::ByteOrder;
+
+/// How a collection's length prefix (`String`, `Vec<T>`, `HashMap<T, V>`)
+/// is encoded on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthEncoding {
+    /// A fixed-width 16-bit prefix.
+    Fixed16,
+    /// A fixed-width 32-bit prefix.
+    Fixed32,
+    /// A 7-bit (LEB128-style) variable-length prefix. See [`crate::read_varint`].
+    Varint,
+}
+
+/// The default value of [`Settings::max_collection_len`].
+///
+/// Chosen to comfortably fit any collection a well-behaved peer would send,
+/// while still rejecting the kind of length prefix a hostile one would use
+/// to trigger a multi-gigabyte `with_capacity` allocation.
+pub const DEFAULT_MAX_COLLECTION_LEN: u64 = 64 * 1024;
+
+/// Settings that control how a value is read from or written to a buffer.
+///
+/// This bundles together the [`ByteOrder`] and [`LengthEncoding`] options so
+/// they don't have to be threaded through `read_from`/`write_to` as separate
+/// arguments as the crate grows more of them. [`StreamReader::read_with`] and
+/// [`StreamWriter::write_with`] take a `Settings` directly, while the plain
+/// `read_from`/`write_to` methods keep working by building one from a bare
+/// [`ByteOrder`].
+///
+/// [`StreamReader::read_with`]: crate::StreamReader::read_with
+/// [`StreamWriter::write_with`]: crate::StreamWriter::write_with
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    /// The byte order to use.
+    pub byte_order: ByteOrder,
+    /// The length-prefix encoding to use for collections.
+    pub length_encoding: LengthEncoding,
+    /// The maximum number of elements a length prefix may declare before a
+    /// collection read is rejected with an `InvalidData` error, instead of
+    /// being trusted to preallocate that much capacity up front. Defaults to
+    /// [`DEFAULT_MAX_COLLECTION_LEN`].
+    pub max_collection_len: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            byte_order: ByteOrder::BigEndian,
+            length_encoding: LengthEncoding::Varint,
+            max_collection_len: DEFAULT_MAX_COLLECTION_LEN,
+        }
+    }
+}
+
+impl Settings {
+    /// Creates new settings with the specified byte order and the default
+    /// length encoding and collection length cap.
+    pub fn new(byte_order: ByteOrder) -> Self {
+        Settings {
+            byte_order,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the byte order.
+    pub fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Sets the length-prefix encoding.
+    pub fn with_length_encoding(mut self, length_encoding: LengthEncoding) -> Self {
+        self.length_encoding = length_encoding;
+        self
+    }
+
+    /// Sets the maximum number of elements a collection's length prefix may
+    /// declare before the read is rejected.
+    pub fn with_max_collection_len(mut self, max_collection_len: u64) -> Self {
+        self.max_collection_len = max_collection_len;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_settings_use_big_endian_and_varint_lengths() {
+        let settings = Settings::default();
+        assert_eq!(ByteOrder::BigEndian, settings.byte_order);
+        assert_eq!(LengthEncoding::Varint, settings.length_encoding);
+        assert_eq!(DEFAULT_MAX_COLLECTION_LEN, settings.max_collection_len);
+    }
+
+    #[test]
+    fn builder_overrides_one_field_at_a_time() {
+        let settings = Settings::default()
+            .with_byte_order(ByteOrder::LittleEndian)
+            .with_length_encoding(LengthEncoding::Fixed32)
+            .with_max_collection_len(16);
+
+        assert_eq!(ByteOrder::LittleEndian, settings.byte_order);
+        assert_eq!(LengthEncoding::Fixed32, settings.length_encoding);
+        assert_eq!(16, settings.max_collection_len);
+    }
+}
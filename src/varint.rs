@@ -0,0 +1,134 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{ByteOrder, StreamReader, StreamWriter};
+
+/// Maximum number of bytes, including the final one, a `u64` varint can be
+/// encoded in.
+/// Anything longer than this cannot represent a valid `u64` and is treated
+/// as corrupt input.
+const MAX_VARINT_BYTES: u32 = 10;
+
+/// Reads a 7-bit (LEB128-style) variable-length encoded unsigned integer
+/// from the specified buffer.
+///
+/// Each byte contributes its lowest 7 bits to the result, starting at the
+/// least significant end, and sets its high bit to indicate that more
+/// bytes follow. Reading stops at the first byte whose high bit is clear.
+/// Byte order has no effect on this encoding.
+pub fn read_varint<R: Read>(buffer: &mut R) -> Result<u64> {
+    let mut result = 0u64;
+    let mut byte = [0u8; 1];
+    for i in 0..MAX_VARINT_BYTES {
+        buffer.read_exact(&mut byte)?;
+        result |= u64::from(byte[0] & 0x7f) << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err(Error::new(
+        ErrorKind::InvalidData,
+        "varint exceeded the maximum number of continuation bytes",
+    ))
+}
+
+/// Writes a 7-bit (LEB128-style) variable-length encoded unsigned integer
+/// to the specified buffer. Byte order has no effect on this encoding.
+pub fn write_varint<W: Write>(buffer: &mut W, mut value: u64) -> Result<()> {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buffer.write_all(&[byte])?;
+        if value == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// A `u64` that is always read and written using the 7-bit (LEB128-style)
+/// variable-length encoding implemented by [`read_varint`]/[`write_varint`],
+/// regardless of the collection [`LengthEncoding`](crate::LengthEncoding) in
+/// effect.
+///
+/// This lets callers opt into compact integer encoding for their own fields
+/// without having to go through [`crate::Settings`]:
+///
+/// ```
+/// use std::io::Cursor;
+/// use bytestream::{ByteOrder, StreamReader, StreamWriter, VarInt};
+///
+/// let mut buffer = Vec::<u8>::new();
+/// VarInt(300).write_to(&mut buffer, ByteOrder::BigEndian).unwrap();
+/// assert_eq!(2, buffer.len());
+///
+/// let mut cursor = Cursor::new(buffer);
+/// let value = VarInt::read_from(&mut cursor, ByteOrder::BigEndian).unwrap();
+/// assert_eq!(VarInt(300), value);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarInt(pub u64);
+
+impl StreamReader for VarInt {
+    fn read_from<R: Read>(buffer: &mut R, _order: ByteOrder) -> Result<Self> {
+        Ok(VarInt(read_varint(buffer)?))
+    }
+}
+
+impl StreamWriter for VarInt {
+    fn write_to<W: Write>(&self, buffer: &mut W, _order: ByteOrder) -> Result<()> {
+        write_varint(buffer, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use test_case::test_case;
+
+    #[test_case(0 ; "zero")]
+    #[test_case(1 ; "one")]
+    #[test_case(127 ; "single byte boundary")]
+    #[test_case(128 ; "two byte boundary")]
+    #[test_case(u16::MAX as u64 ; "u16 max")]
+    #[test_case(u32::MAX as u64 ; "u32 max")]
+    #[test_case(u64::MAX ; "u64 max")]
+    fn round_trips(value: u64) {
+        let mut buffer = Vec::<u8>::new();
+        write_varint(&mut buffer, value).unwrap();
+
+        let mut cursor = Cursor::new(buffer);
+        let result = read_varint(&mut cursor).unwrap();
+
+        assert_eq!(value, result);
+    }
+
+    #[test]
+    fn zero_is_a_single_byte() {
+        let mut buffer = Vec::<u8>::new();
+        write_varint(&mut buffer, 0).unwrap();
+        assert_eq!(vec![0x00], buffer);
+    }
+
+    #[test]
+    fn too_many_continuation_bytes_is_an_error() {
+        let buffer = vec![0x80u8; 11];
+        let mut cursor = Cursor::new(buffer);
+        let result = read_varint(&mut cursor);
+        assert!(result.is_err());
+    }
+
+    #[test_case(ByteOrder::BigEndian ; "big endian")]
+    #[test_case(ByteOrder::LittleEndian ; "little endian")]
+    fn var_int_round_trips_and_is_order_independent(order: ByteOrder) {
+        let mut buffer = Vec::<u8>::new();
+        VarInt(300).write_to(&mut buffer, order).unwrap();
+        assert_eq!(vec![0xAC, 0x02], buffer);
+
+        let mut cursor = Cursor::new(buffer);
+        let result = VarInt::read_from(&mut cursor, order).unwrap();
+        assert_eq!(VarInt(300), result);
+    }
+}